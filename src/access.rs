@@ -0,0 +1,122 @@
+use crate::{wrappers, SecurityDescriptor};
+use std::ffi::OsStr;
+use std::io;
+use winapi::shared::winerror::ERROR_ACCESS_DENIED;
+use winapi::um::winnt::{
+    FILE_GENERIC_EXECUTE, FILE_GENERIC_READ, FILE_GENERIC_WRITE, GENERIC_EXECUTE, GENERIC_MAPPING,
+    GENERIC_READ, GENERIC_WRITE,
+};
+
+bitflags::bitflags! {
+    /// Which kinds of access to probe for with [`access`]
+    ///
+    /// Mirrors the mode bits of POSIX `access(2)`/the `faccess` crate, mapped
+    /// onto the closest Win32 generic rights for a file.
+    pub struct AccessMode: u32 {
+        /// The object exists (no access right is checked)
+        const EXISTS = 0b000;
+        /// The caller can read the object
+        const READ = 0b001;
+        /// The caller can write the object
+        const WRITE = 0b010;
+        /// The caller can execute the object
+        const EXECUTE = 0b100;
+    }
+}
+
+/// RAII guard around `ImpersonateSelf`/`RevertToSelf`
+///
+/// Ensures the calling thread always reverts to its primary token, even if
+/// an error is returned (or panics) between the two calls.
+struct ImpersonationGuard;
+
+impl ImpersonationGuard {
+    fn new() -> io::Result<Self> {
+        wrappers::ImpersonateSelf()?;
+        Ok(Self)
+    }
+}
+
+impl Drop for ImpersonationGuard {
+    fn drop(&mut self) {
+        let _ = wrappers::RevertToSelf();
+    }
+}
+
+/// Check whether the current user likely has the requested access to `path`
+///
+/// `AccessMode::EXISTS` alone is answered with a plain metadata lookup,
+/// since proving existence shouldn't require `READ_CONTROL` on the object's
+/// `SecurityDescriptor` (or impersonating the caller at all). Any of
+/// `READ`/`WRITE`/`EXECUTE` opens the object's `SecurityDescriptor` (via
+/// `lookup_path`), impersonates the calling thread's own token
+/// (`ImpersonateSelf` + `OpenThreadToken`, reverted via `RevertToSelf`
+/// before returning), and runs that token through `AccessCheck` against the
+/// descriptor, translating `mode` into `FILE_GENERIC_*` rights via a
+/// `GENERIC_MAPPING`. Returns `Ok(())` only if every bit set in `mode` was
+/// granted.
+pub fn access<S: AsRef<OsStr> + ?Sized>(path: &S, mode: AccessMode) -> io::Result<()> {
+    let mut desired = 0;
+    if mode.contains(AccessMode::READ) {
+        desired |= GENERIC_READ;
+    }
+    if mode.contains(AccessMode::WRITE) {
+        desired |= GENERIC_WRITE;
+    }
+    if mode.contains(AccessMode::EXECUTE) {
+        desired |= GENERIC_EXECUTE;
+    }
+
+    if desired == 0 {
+        // Pure existence probe: prove the object is there without needing
+        // READ_CONTROL to read its SecurityDescriptor, and without
+        // impersonating the caller.
+        std::fs::metadata(path.as_ref())?;
+        return Ok(());
+    }
+
+    let sd = SecurityDescriptor::lookup_path(path)?;
+    let _impersonation = ImpersonationGuard::new()?;
+    let token = wrappers::OpenThreadToken(true)?;
+
+    let mapping = GENERIC_MAPPING {
+        GenericRead: FILE_GENERIC_READ,
+        GenericWrite: FILE_GENERIC_WRITE,
+        GenericExecute: FILE_GENERIC_EXECUTE,
+        GenericAll: FILE_GENERIC_READ | FILE_GENERIC_WRITE | FILE_GENERIC_EXECUTE,
+    };
+
+    let (_granted, allowed) = wrappers::AccessCheck(&sd, &token, desired, mapping)?;
+
+    if allowed {
+        Ok(())
+    } else {
+        Err(io::Error::from_raw_os_error(ERROR_ACCESS_DENIED as i32))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn exists_probe_does_not_require_security_descriptor_access() -> io::Result<()> {
+        let path = std::env::temp_dir().join(format!(
+            "windows-permissions-rs-access-exists-{}",
+            std::process::id()
+        ));
+        std::fs::File::create(&path)?;
+
+        let result = access(&path, AccessMode::EXISTS);
+        std::fs::remove_file(&path)?;
+        result
+    }
+
+    #[test]
+    fn exists_probe_fails_for_missing_path() {
+        let path =
+            std::env::temp_dir().join("windows-permissions-rs-access-definitely-does-not-exist");
+
+        assert!(access(&path, AccessMode::EXISTS).is_err());
+    }
+}
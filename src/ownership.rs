@@ -0,0 +1,80 @@
+use crate::{wrappers, LocalBox, SecurityDescriptor, Sid};
+use std::ffi::OsStr;
+use std::io;
+use winapi::um::winnt::WinBuiltinAdministratorsSid;
+
+/// Get the SID of the user running the current process
+///
+/// This is a direct call to `wrappers::OpenProcessToken` followed by
+/// `wrappers::GetTokenInformationUser`.
+pub fn current_user_sid() -> io::Result<LocalBox<Sid>> {
+    let token = wrappers::OpenProcessToken()?;
+    wrappers::GetTokenInformationUser(&token)
+}
+
+/// Get the well-known SID for the local Administrators group
+///
+/// This is a direct call to `wrappers::CreateWellKnownSid`.
+pub fn administrators_sid() -> io::Result<LocalBox<Sid>> {
+    wrappers::CreateWellKnownSid(WinBuiltinAdministratorsSid)
+}
+
+/// Check that the owner of the object at `path` is one of `allowed`
+///
+/// Fetches the owner via `SecurityDescriptor::lookup_path(path).owner()`,
+/// typically compared against `current_user_sid()`/`administrators_sid()`.
+pub fn validate_path_ownership<S: AsRef<OsStr> + ?Sized>(
+    path: &S,
+    allowed: &[&Sid],
+) -> io::Result<()> {
+    let sd = SecurityDescriptor::lookup_path(path)?;
+
+    match sd.owner() {
+        Some(owner) if allowed.iter().any(|&sid| sid == owner) => Ok(()),
+        _ => Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "object owner is not in the allowed set",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn unique_temp_path(tag: &str) -> std::path::PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!(
+            "windows-permissions-rs-ownership-{}-{}-{}",
+            tag,
+            std::process::id(),
+            n
+        ))
+    }
+
+    #[test]
+    fn validate_path_ownership_accepts_current_user() -> io::Result<()> {
+        let path = unique_temp_path("accept");
+        std::fs::File::create(&path)?;
+
+        let me = current_user_sid()?;
+        let result = validate_path_ownership(&path, &[&me]);
+
+        std::fs::remove_file(&path)?;
+        result
+    }
+
+    #[test]
+    fn validate_path_ownership_rejects_empty_allow_list() -> io::Result<()> {
+        let path = unique_temp_path("reject");
+        std::fs::File::create(&path)?;
+
+        let result = validate_path_ownership(&path, &[]);
+        std::fs::remove_file(&path)?;
+
+        assert!(result.is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,188 @@
+use crate::constants::{AccessRights, AceFlags};
+use crate::{wrappers, Ace, Acl, LocalBox, Sid};
+use std::io;
+use std::mem::size_of;
+use winapi::um::securitybaseapi::GetLengthSid;
+use winapi::um::winnt::{ACCESS_ALLOWED_ACE, ACL};
+
+struct PendingAce {
+    allow: bool,
+    flags: AceFlags,
+    access_rights: AccessRights,
+    sid: LocalBox<Sid>,
+}
+
+/// A builder for constructing a mutable [`Acl`] one ACE at a time
+///
+/// Wraps `InitializeAcl` plus `AddAccessAllowedAceEx`/`AddAccessDeniedAceEx`,
+/// mirroring `SecurityDescriptorBuilder`'s owned/buildable approach to the
+/// otherwise read-only `Acl`/`Ace` types. The result is a `LocalBox<Acl>`
+/// that can be handed to `SecurityDescriptorBuilder::dacl`/`sacl` or to
+/// `wrappers::SetNamedSecurityInfo`/`SetSecurityInfo`.
+#[derive(Default)]
+pub struct AclBuilder {
+    pending: Vec<PendingAce>,
+}
+
+impl AclBuilder {
+    /// Create an empty builder, with no ACEs queued up
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queue an access-allowed ACE granting `access_rights` to `sid`
+    pub fn add_ace(
+        &mut self,
+        sid: LocalBox<Sid>,
+        access_rights: AccessRights,
+        flags: AceFlags,
+    ) -> &mut Self {
+        self.pending.push(PendingAce {
+            allow: true,
+            flags,
+            access_rights,
+            sid,
+        });
+        self
+    }
+
+    /// Queue an access-denied ACE denying `access_rights` to `sid`
+    pub fn add_deny_ace(
+        &mut self,
+        sid: LocalBox<Sid>,
+        access_rights: AccessRights,
+        flags: AceFlags,
+    ) -> &mut Self {
+        self.pending.push(PendingAce {
+            allow: false,
+            flags,
+            access_rights,
+            sid,
+        });
+        self
+    }
+
+    /// Drop the queued ACE that would end up at position `index`
+    ///
+    /// Returns `false` (and does nothing) if `index` is out of range, rather
+    /// than panicking in the middle of a builder chain.
+    pub fn remove_ace_at(&mut self, index: usize) -> bool {
+        if index < self.pending.len() {
+            self.pending.remove(index);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Finalize the builder into a `LocalBox<Acl>`
+    ///
+    /// Sizes the backing buffer from the queued ACEs, then adds them in
+    /// order via `InitializeAcl` and `AddAccessAllowedAceEx`/
+    /// `AddAccessDeniedAceEx`.
+    pub fn build(&self) -> io::Result<LocalBox<Acl>> {
+        let mut size = size_of::<ACL>() as u32;
+        for ace in &self.pending {
+            let sid_len = unsafe { GetLengthSid(&*ace.sid as *const Sid as *mut _) };
+            size += size_of::<ACCESS_ALLOWED_ACE>() as u32 - size_of::<u32>() as u32 + sid_len;
+        }
+
+        let mut acl = wrappers::InitializeAcl(size)?;
+
+        for ace in &self.pending {
+            if ace.allow {
+                wrappers::AddAccessAllowedAceEx(&mut acl, ace.flags, ace.access_rights, &ace.sid)?;
+            } else {
+                wrappers::AddAccessDeniedAceEx(&mut acl, ace.flags, ace.access_rights, &ace.sid)?;
+            }
+        }
+
+        Ok(acl)
+    }
+}
+
+/// An iterator over the ACEs in an [`Acl`], returned by `Acl::aces`
+pub struct AceIter<'a> {
+    acl: &'a Acl,
+    index: u32,
+    count: u32,
+}
+
+impl<'a> Iterator for AceIter<'a> {
+    type Item = io::Result<&'a Ace>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.count {
+            return None;
+        }
+
+        let ace = wrappers::GetAce(self.acl, self.index);
+        self.index += 1;
+        Some(ace)
+    }
+}
+
+impl Acl {
+    /// Get the number of ACEs in this ACL
+    ///
+    /// This is a direct call to `wrappers::GetAclInformation`.
+    pub fn ace_count(&self) -> io::Result<u32> {
+        wrappers::GetAclInformation(self).map(|info| info.AceCount)
+    }
+
+    /// Iterate over the ACEs in this ACL, in order
+    ///
+    /// This is a direct call to `wrappers::GetAce` for each index up to
+    /// `ace_count`.
+    pub fn aces(&self) -> io::Result<AceIter<'_>> {
+        let count = self.ace_count()?;
+        Ok(AceIter {
+            acl: self,
+            index: 0,
+            count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::administrators_sid;
+
+    #[test]
+    fn build_then_iterate_round_trips_ace_count() -> io::Result<()> {
+        let mut builder = AclBuilder::new();
+        builder.add_ace(
+            administrators_sid()?,
+            AccessRights::all(),
+            AceFlags::empty(),
+        );
+        builder.add_deny_ace(
+            administrators_sid()?,
+            AccessRights::all(),
+            AceFlags::empty(),
+        );
+
+        let acl = builder.build()?;
+        assert_eq!(acl.ace_count()?, 2);
+
+        let aces = acl.aces()?.collect::<io::Result<Vec<_>>>()?;
+        assert_eq!(aces.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove_ace_at_out_of_range_is_a_no_op() -> io::Result<()> {
+        let mut builder = AclBuilder::new();
+        builder.add_ace(
+            administrators_sid()?,
+            AccessRights::all(),
+            AceFlags::empty(),
+        );
+
+        assert!(!builder.remove_ace_at(1));
+        assert_eq!(builder.pending.len(), 1);
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::constants::{SeObjectType, SecurityInformation};
-use crate::{wrappers, Acl, LocalBox, Sid};
+use crate::{wrappers, Acl, LocalBox, SecurityAttributes, Sid};
 use std::ffi::{OsStr, OsString};
 use std::fmt;
 use std::fs::File;
@@ -49,6 +49,47 @@ impl SecurityDescriptor {
     }
 }
 
+impl SecurityDescriptor {
+    /// Write this descriptor onto the file at a given path
+    ///
+    /// This is a direct call to `wrappers::SetNamedSecurityInfo`, pulling the
+    /// owner, group, DACL and SACL out of `self` via the `GetSecurityDescriptor*`
+    /// getters. All four are passed through, but only the components selected
+    /// by `info` are written, so a descriptor parsed from SDDL can be stamped
+    /// onto a file without disturbing the components `info` leaves out.
+    pub fn apply_to_path<S: AsRef<OsStr> + ?Sized>(
+        &self,
+        path: &S,
+        info: SecurityInformation,
+    ) -> io::Result<()> {
+        wrappers::SetNamedSecurityInfo(
+            path.as_ref(),
+            SeObjectType::SE_FILE_OBJECT,
+            info,
+            self.owner(),
+            self.group(),
+            self.dacl(),
+            self.sacl(),
+        )
+    }
+
+    /// Write this descriptor onto an open file
+    ///
+    /// This is a direct call to `wrappers::SetSecurityInfo` with the same
+    /// owner/group/DACL/SACL extraction as `apply_to_path`.
+    pub fn apply_to_file(&self, file: &File, info: SecurityInformation) -> io::Result<()> {
+        wrappers::SetSecurityInfo(
+            file,
+            SeObjectType::SE_FILE_OBJECT,
+            info,
+            self.owner(),
+            self.group(),
+            self.dacl(),
+            self.sacl(),
+        )
+    }
+}
+
 impl SecurityDescriptor {
     pub unsafe fn ref_from_nonnull<'s>(ptr: NonNull<c_void>) -> &'s Self {
         let sd_ref = std::mem::transmute::<NonNull<c_void>, &Self>(ptr);
@@ -93,6 +134,19 @@ impl SecurityDescriptor {
         wrappers::GetSecurityDescriptorSacl(self)
             .expect("Valid SecurityDescriptor failed to get sacl")
     }
+
+    /// Package this descriptor into a `SECURITY_ATTRIBUTES` struct
+    ///
+    /// The result borrows `self`, and is suitable for passing to object
+    /// creation APIs such as `CreateNamedPipeW`/`CreateFileW`.
+    pub fn as_security_attributes(&self, inherit_handle: bool) -> SecurityAttributes<'_> {
+        SecurityAttributes::new(self, inherit_handle)
+    }
+
+    /// Check whether this descriptor's owner matches `sid`
+    pub fn is_owned_by(&self, sid: &Sid) -> bool {
+        self.owner() == Some(sid)
+    }
 }
 
 impl fmt::Debug for SecurityDescriptor {
@@ -195,4 +249,17 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn is_owned_by_matches_only_the_owner() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "O:AOG:SY".parse()?;
+
+        let owner: LocalBox<Sid> = "AO".parse()?;
+        assert!(sd.is_owned_by(&owner));
+
+        let other: LocalBox<Sid> = "SY".parse()?;
+        assert!(!sd.is_owned_by(&other));
+
+        Ok(())
+    }
 }
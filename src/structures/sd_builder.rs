@@ -0,0 +1,139 @@
+use crate::{wrappers, Acl, AclBuilder, LocalBox, SecurityDescriptor, Sid};
+use std::io;
+
+/// What `SecurityDescriptorBuilder::build` should do about the DACL
+enum DaclSetting {
+    /// No DACL was set explicitly: `build` fills in an empty (deny-all) DACL
+    /// rather than leaving the descriptor with no DACL at all
+    Unset,
+    /// A DACL was set explicitly via `dacl`
+    Explicit(LocalBox<Acl>),
+    /// The caller opted into a descriptor with no DACL via `no_dacl`
+    ExplicitlyNone,
+}
+
+impl Default for DaclSetting {
+    fn default() -> Self {
+        DaclSetting::Unset
+    }
+}
+
+/// A builder for constructing a self-relative [`SecurityDescriptor`] from
+/// scratch
+///
+/// Unlike `SecurityDescriptor::lookup_path`/`lookup_file`, which read a
+/// descriptor that already exists on an object, this lets a caller assemble
+/// one owner/group/DACL/SACL at a time and turn the result into a
+/// `LocalBox<SecurityDescriptor>` via `MakeSelfRelativeSD`, ready to be
+/// written out with `apply_to_path`/`apply_to_file` or packaged into a
+/// `SECURITY_ATTRIBUTES` with `as_security_attributes`.
+#[derive(Default)]
+pub struct SecurityDescriptorBuilder {
+    owner: Option<LocalBox<Sid>>,
+    group: Option<LocalBox<Sid>>,
+    dacl: DaclSetting,
+    sacl: Option<LocalBox<Acl>>,
+}
+
+impl SecurityDescriptorBuilder {
+    /// Create an empty builder, with no owner or group set and an empty
+    /// (deny-all) DACL
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the owner SID
+    pub fn owner(mut self, owner: LocalBox<Sid>) -> Self {
+        self.owner = Some(owner);
+        self
+    }
+
+    /// Set the group SID
+    pub fn group(mut self, group: LocalBox<Sid>) -> Self {
+        self.group = Some(group);
+        self
+    }
+
+    /// Set the DACL
+    pub fn dacl(mut self, dacl: LocalBox<Acl>) -> Self {
+        self.dacl = DaclSetting::Explicit(dacl);
+        self
+    }
+
+    /// Opt into a descriptor with **no DACL at all**, rather than the empty
+    /// (deny-all) DACL `build` otherwise fills in
+    ///
+    /// # Security
+    ///
+    /// A `SecurityDescriptor` with no DACL grants every principal full
+    /// access (a "NULL DACL"), same as the underlying
+    /// `SetSecurityDescriptorDacl(..., None)` call. Prefer leaving the DACL
+    /// unset or calling `dacl` with an explicit ACL; only reach for this
+    /// when a NULL DACL is genuinely what's wanted.
+    pub fn no_dacl(mut self) -> Self {
+        self.dacl = DaclSetting::ExplicitlyNone;
+        self
+    }
+
+    /// Set the SACL
+    pub fn sacl(mut self, sacl: LocalBox<Acl>) -> Self {
+        self.sacl = Some(sacl);
+        self
+    }
+
+    /// Assemble the pieces set so far into a self-relative `SecurityDescriptor`
+    ///
+    /// If no DACL was set, this fills in an empty (deny-all) ACL rather than
+    /// leaving the descriptor with no DACL, since a NULL DACL grants every
+    /// principal full access. Call `no_dacl` instead of `dacl` to opt into
+    /// that behavior explicitly.
+    pub fn build(self) -> io::Result<LocalBox<SecurityDescriptor>> {
+        let mut absolute = wrappers::InitializeSecurityDescriptor()?;
+
+        let dacl = match self.dacl {
+            DaclSetting::Unset => Some(AclBuilder::new().build()?),
+            DaclSetting::Explicit(acl) => Some(acl),
+            DaclSetting::ExplicitlyNone => None,
+        };
+
+        wrappers::SetSecurityDescriptorOwner(&mut absolute, self.owner.as_deref())?;
+        wrappers::SetSecurityDescriptorGroup(&mut absolute, self.group.as_deref())?;
+        wrappers::SetSecurityDescriptorDacl(&mut absolute, dacl.as_deref())?;
+        wrappers::SetSecurityDescriptorSacl(&mut absolute, self.sacl.as_deref())?;
+
+        wrappers::MakeSelfRelativeSD(&absolute)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn build_sets_requested_owner() -> io::Result<()> {
+        let owner: LocalBox<Sid> = "SY".parse()?;
+        let expected: LocalBox<Sid> = "SY".parse()?;
+
+        let sd = SecurityDescriptorBuilder::new().owner(owner).build()?;
+
+        assert_eq!(sd.owner(), Some(&*expected));
+        Ok(())
+    }
+
+    #[test]
+    fn default_dacl_is_empty_not_null() -> io::Result<()> {
+        let sd = SecurityDescriptorBuilder::new().build()?;
+
+        // An empty DACL is present (deny-all), not absent (allow-all)
+        assert!(sd.dacl().is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn no_dacl_opts_into_null_dacl() -> io::Result<()> {
+        let sd = SecurityDescriptorBuilder::new().no_dacl().build()?;
+
+        assert!(sd.dacl().is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+use crate::SecurityDescriptor;
+use std::marker::PhantomData;
+use std::mem::size_of;
+use winapi::ctypes::c_void;
+use winapi::um::minwinbase::SECURITY_ATTRIBUTES;
+
+/// A `SECURITY_ATTRIBUTES` struct tied to the `SecurityDescriptor` it was
+/// built from
+///
+/// This borrows the descriptor rather than cloning it, so a
+/// `SecurityAttributes` can't outlive the `SecurityDescriptor` that created
+/// it. Pass `as_ptr()` to APIs expecting a `LPSECURITY_ATTRIBUTES`, such as
+/// `CreateNamedPipeW` or `CreateFileW`.
+pub struct SecurityAttributes<'sd> {
+    raw: SECURITY_ATTRIBUTES,
+    _descriptor: PhantomData<&'sd SecurityDescriptor>,
+}
+
+impl<'sd> SecurityAttributes<'sd> {
+    pub(crate) fn new(descriptor: &'sd SecurityDescriptor, inherit_handle: bool) -> Self {
+        Self {
+            raw: SECURITY_ATTRIBUTES {
+                nLength: size_of::<SECURITY_ATTRIBUTES>() as u32,
+                lpSecurityDescriptor: descriptor as *const SecurityDescriptor as *mut c_void,
+                bInheritHandle: inherit_handle as i32,
+            },
+            _descriptor: PhantomData,
+        }
+    }
+
+    /// Get a pointer suitable for passing to Win32 APIs expecting a
+    /// `LPSECURITY_ATTRIBUTES`
+    pub fn as_ptr(&self) -> *mut SECURITY_ATTRIBUTES {
+        &self.raw as *const SECURITY_ATTRIBUTES as *mut _
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::LocalBox;
+    use std::io;
+
+    #[test]
+    fn as_ptr_reflects_descriptor_and_inherit_handle() -> io::Result<()> {
+        let sd: LocalBox<SecurityDescriptor> = "O:SY".parse()?;
+        let attrs = sd.as_security_attributes(true);
+
+        let raw = unsafe { &*attrs.as_ptr() };
+        assert_eq!(raw.nLength, size_of::<SECURITY_ATTRIBUTES>() as u32);
+        assert_eq!(raw.bInheritHandle, 1);
+        assert_eq!(
+            raw.lpSecurityDescriptor,
+            &*sd as *const SecurityDescriptor as *mut c_void
+        );
+
+        Ok(())
+    }
+}
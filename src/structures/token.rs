@@ -0,0 +1,29 @@
+use winapi::shared::ntdef::HANDLE;
+use winapi::um::handleapi::CloseHandle;
+
+/// An owned access token handle
+///
+/// Returned by token-acquiring wrappers such as `wrappers::OpenThreadToken`
+/// and `wrappers::OpenProcessToken`. The underlying handle is closed when
+/// this value is dropped.
+pub struct Token {
+    handle: HANDLE,
+}
+
+impl Token {
+    pub(crate) unsafe fn from_raw_handle(handle: HANDLE) -> Self {
+        Self { handle }
+    }
+
+    pub(crate) fn as_raw_handle(&self) -> HANDLE {
+        self.handle
+    }
+}
+
+impl Drop for Token {
+    fn drop(&mut self) {
+        unsafe {
+            CloseHandle(self.handle);
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::structures::token::Token;
+use crate::SecurityDescriptor;
+use std::io;
+use std::mem::{size_of, zeroed};
+use winapi::um::securitybaseapi::{AccessCheck as Win32AccessCheck, MapGenericMask};
+use winapi::um::winnt::{GENERIC_MAPPING, PRIVILEGE_SET};
+
+/// Wraps [`AccessCheck`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-accesscheck)
+///
+/// `desired_access` may contain generic rights; they are mapped to
+/// object-specific rights via `mapping` before the check, same as the Win32
+/// API itself would do internally for a generic-only caller.
+///
+/// Returns the access actually granted, plus whether `desired_access` was
+/// granted in full. `access_status` being `false` is a legitimate answer
+/// (the object's ACL just doesn't allow it) rather than a failure of this
+/// wrapper, so it's reported instead of turned into an `Err`; only an
+/// actual `AccessCheck` API failure is.
+#[allow(non_snake_case)]
+pub fn AccessCheck(
+    sd: &SecurityDescriptor,
+    token: &Token,
+    desired_access: u32,
+    mapping: GENERIC_MAPPING,
+) -> io::Result<(u32, bool)> {
+    let mut desired_access = desired_access;
+    unsafe { MapGenericMask(&mut desired_access, &mapping) };
+
+    let mut privilege_set: PRIVILEGE_SET = unsafe { zeroed() };
+    let mut privilege_set_len = size_of::<PRIVILEGE_SET>() as u32;
+    let mut granted_access: u32 = 0;
+    let mut access_status: i32 = 0;
+
+    let result = unsafe {
+        Win32AccessCheck(
+            sd as *const SecurityDescriptor as *mut _,
+            token.as_raw_handle(),
+            desired_access,
+            &mapping as *const GENERIC_MAPPING as *mut _,
+            &mut privilege_set,
+            &mut privilege_set_len,
+            &mut granted_access,
+            &mut access_status,
+        )
+    };
+
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok((granted_access, access_status != 0))
+}
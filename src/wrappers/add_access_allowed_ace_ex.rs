@@ -0,0 +1,30 @@
+use crate::constants::{AccessRights, AceFlags};
+use crate::{Acl, Sid};
+use std::io;
+use winapi::um::securitybaseapi::AddAccessAllowedAceEx as Win32AddAccessAllowedAceEx;
+use winapi::um::winnt::{ACL, ACL_REVISION};
+
+/// Wraps [`AddAccessAllowedAceEx`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessallowedaceex)
+#[allow(non_snake_case)]
+pub fn AddAccessAllowedAceEx(
+    acl: &mut Acl,
+    flags: AceFlags,
+    access_rights: AccessRights,
+    sid: &Sid,
+) -> io::Result<()> {
+    let result = unsafe {
+        Win32AddAccessAllowedAceEx(
+            acl as *mut Acl as *mut ACL,
+            ACL_REVISION as u32,
+            flags.bits(),
+            access_rights.bits(),
+            sid as *const Sid as *mut _,
+        )
+    };
+
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
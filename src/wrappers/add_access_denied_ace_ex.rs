@@ -0,0 +1,30 @@
+use crate::constants::{AccessRights, AceFlags};
+use crate::{Acl, Sid};
+use std::io;
+use winapi::um::securitybaseapi::AddAccessDeniedAceEx as Win32AddAccessDeniedAceEx;
+use winapi::um::winnt::{ACL, ACL_REVISION};
+
+/// Wraps [`AddAccessDeniedAceEx`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-addaccessdeniedaceex)
+#[allow(non_snake_case)]
+pub fn AddAccessDeniedAceEx(
+    acl: &mut Acl,
+    flags: AceFlags,
+    access_rights: AccessRights,
+    sid: &Sid,
+) -> io::Result<()> {
+    let result = unsafe {
+        Win32AddAccessDeniedAceEx(
+            acl as *mut Acl as *mut ACL,
+            ACL_REVISION as u32,
+            flags.bits(),
+            access_rights.bits(),
+            sid as *const Sid as *mut _,
+        )
+    };
+
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
@@ -0,0 +1,27 @@
+use crate::{LocalBox, Sid};
+use std::io;
+use std::ptr;
+use winapi::um::securitybaseapi::CreateWellKnownSid as Win32CreateWellKnownSid;
+use winapi::um::winbase::{LocalAlloc, LocalFree, LMEM_FIXED};
+use winapi::um::winnt::{SECURITY_MAX_SID_SIZE, WELL_KNOWN_SID_TYPE};
+
+/// Wraps [`CreateWellKnownSid`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-createwellknownsid)
+#[allow(non_snake_case)]
+pub fn CreateWellKnownSid(sid_type: WELL_KNOWN_SID_TYPE) -> io::Result<LocalBox<Sid>> {
+    let mut len = SECURITY_MAX_SID_SIZE as u32;
+    let buffer = unsafe { LocalAlloc(LMEM_FIXED, len as usize) };
+    if buffer.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+
+    let result =
+        unsafe { Win32CreateWellKnownSid(sid_type, ptr::null_mut(), buffer as *mut _, &mut len) };
+
+    if result != 0 {
+        Ok(unsafe { LocalBox::from_raw(buffer as *mut _) })
+    } else {
+        let err = io::Error::last_os_error();
+        unsafe { LocalFree(buffer) };
+        Err(err)
+    }
+}
@@ -0,0 +1,19 @@
+use crate::{Ace, Acl};
+use std::io;
+use std::ptr;
+use winapi::ctypes::c_void;
+use winapi::um::securitybaseapi::GetAce as Win32GetAce;
+
+/// Wraps [`GetAce`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getace)
+#[allow(non_snake_case)]
+pub fn GetAce(acl: &Acl, index: u32) -> io::Result<&Ace> {
+    let mut ace_ptr: *mut c_void = ptr::null_mut();
+
+    let result = unsafe { Win32GetAce(acl as *const Acl as *mut _, index, &mut ace_ptr) };
+
+    if result != 0 {
+        Ok(unsafe { &*(ace_ptr as *const Ace) })
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
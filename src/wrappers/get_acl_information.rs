@@ -0,0 +1,27 @@
+use crate::Acl;
+use std::io;
+use std::mem::{size_of, zeroed};
+use winapi::um::securitybaseapi::GetAclInformation as Win32GetAclInformation;
+use winapi::um::winnt::{AclSizeInformation, ACL_SIZE_INFORMATION};
+
+/// Wraps [`GetAclInformation`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-getaclinformation)
+/// for the `AclSizeInformation` class
+#[allow(non_snake_case)]
+pub fn GetAclInformation(acl: &Acl) -> io::Result<ACL_SIZE_INFORMATION> {
+    let mut info: ACL_SIZE_INFORMATION = unsafe { zeroed() };
+
+    let result = unsafe {
+        Win32GetAclInformation(
+            acl as *const Acl as *mut _,
+            &mut info as *mut _ as *mut _,
+            size_of::<ACL_SIZE_INFORMATION>() as u32,
+            AclSizeInformation,
+        )
+    };
+
+    if result != 0 {
+        Ok(info)
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
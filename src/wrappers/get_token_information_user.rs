@@ -0,0 +1,67 @@
+use crate::structures::token::Token;
+use crate::{LocalBox, Sid};
+use std::io;
+use std::ptr;
+use winapi::um::securitybaseapi::GetTokenInformation as Win32GetTokenInformation;
+use winapi::um::securitybaseapi::{CopySid, GetLengthSid};
+use winapi::um::winbase::{LocalAlloc, LocalFree, LMEM_FIXED};
+use winapi::um::winnt::{TokenUser, TOKEN_USER};
+
+/// Wraps [`GetTokenInformation`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-gettokeninformation)
+/// for the `TokenUser` information class
+///
+/// Returns an owned copy of the SID for the user represented by `token`,
+/// since the one embedded in the `TOKEN_USER` buffer only lives as long as
+/// that buffer does.
+#[allow(non_snake_case)]
+pub fn GetTokenInformationUser(token: &Token) -> io::Result<LocalBox<Sid>> {
+    let mut len = 0u32;
+
+    // Assumptions:
+    // - With a null/zero-length buffer, this call always fails and fills in
+    //   the required len
+    unsafe {
+        Win32GetTokenInformation(
+            token.as_raw_handle(),
+            TokenUser,
+            ptr::null_mut(),
+            0,
+            &mut len,
+        );
+    }
+    if len == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let mut buffer = vec![0u8; len as usize];
+    let result = unsafe {
+        Win32GetTokenInformation(
+            token.as_raw_handle(),
+            TokenUser,
+            buffer.as_mut_ptr() as *mut _,
+            len,
+            &mut len,
+        )
+    };
+    if result == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let token_user = unsafe { &*(buffer.as_ptr() as *const TOKEN_USER) };
+    let sid_ptr = token_user.User.Sid;
+    let sid_len = unsafe { GetLengthSid(sid_ptr) };
+
+    let owned_sid = unsafe { LocalAlloc(LMEM_FIXED, sid_len as usize) };
+    if owned_sid.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+
+    let copy_result = unsafe { CopySid(sid_len, owned_sid as *mut _, sid_ptr) };
+    if copy_result != 0 {
+        Ok(unsafe { LocalBox::from_raw(owned_sid as *mut _) })
+    } else {
+        let err = io::Error::last_os_error();
+        unsafe { LocalFree(owned_sid) };
+        Err(err)
+    }
+}
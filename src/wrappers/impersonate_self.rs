@@ -0,0 +1,18 @@
+use std::io;
+use winapi::um::securitybaseapi::ImpersonateSelf as Win32ImpersonateSelf;
+use winapi::um::winnt::SecurityImpersonation;
+
+/// Wraps [`ImpersonateSelf`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-impersonateself)
+///
+/// Always impersonates at the `SecurityImpersonation` level, which is enough
+/// for the calling thread to acquire its own token via `OpenThreadToken`.
+#[allow(non_snake_case)]
+pub fn ImpersonateSelf() -> io::Result<()> {
+    let result = unsafe { Win32ImpersonateSelf(SecurityImpersonation) };
+
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
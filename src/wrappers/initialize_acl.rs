@@ -0,0 +1,29 @@
+use crate::{Acl, LocalBox};
+use std::io;
+use winapi::um::securitybaseapi::InitializeAcl as Win32InitializeAcl;
+use winapi::um::winbase::{LocalAlloc, LocalFree, LMEM_FIXED};
+use winapi::um::winnt::{ACL, ACL_REVISION};
+
+/// Wraps [`InitializeAcl`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-initializeacl)
+///
+/// Allocates `size` bytes with `LocalAlloc` and initializes them as an empty
+/// ACL, ready for `AddAccessAllowedAceEx`/`AddAccessDeniedAceEx` to append
+/// ACEs into. The caller is responsible for sizing the buffer large enough
+/// to hold every ACE it intends to add.
+#[allow(non_snake_case)]
+pub fn InitializeAcl(size: u32) -> io::Result<LocalBox<Acl>> {
+    let buffer = unsafe { LocalAlloc(LMEM_FIXED, size as usize) };
+    if buffer.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+
+    let result = unsafe { Win32InitializeAcl(buffer as *mut ACL, size, ACL_REVISION as u32) };
+
+    if result != 0 {
+        Ok(unsafe { LocalBox::from_raw(buffer as *mut _) })
+    } else {
+        let err = io::Error::last_os_error();
+        unsafe { LocalFree(buffer) };
+        Err(err)
+    }
+}
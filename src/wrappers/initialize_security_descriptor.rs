@@ -0,0 +1,27 @@
+use std::io;
+use std::mem::zeroed;
+use winapi::um::securitybaseapi::InitializeSecurityDescriptor as Win32InitializeSecurityDescriptor;
+use winapi::um::winnt::{SECURITY_DESCRIPTOR, SECURITY_DESCRIPTOR_REVISION};
+
+/// Wraps [`InitializeSecurityDescriptor`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-initializesecuritydescriptor)
+///
+/// Returns a fresh, absolute `SECURITY_DESCRIPTOR` with no owner, group,
+/// DACL or SACL set. This is only useful as a staging area for the
+/// `SetSecurityDescriptor*` wrappers, followed by `MakeSelfRelativeSD`.
+#[allow(non_snake_case)]
+pub fn InitializeSecurityDescriptor() -> io::Result<SECURITY_DESCRIPTOR> {
+    let mut raw = unsafe { zeroed::<SECURITY_DESCRIPTOR>() };
+
+    let result = unsafe {
+        Win32InitializeSecurityDescriptor(
+            &mut raw as *mut _ as *mut _,
+            SECURITY_DESCRIPTOR_REVISION,
+        )
+    };
+
+    if result != 0 {
+        Ok(raw)
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
@@ -0,0 +1,45 @@
+use crate::{LocalBox, SecurityDescriptor};
+use std::io;
+use std::ptr;
+use winapi::um::securitybaseapi::MakeSelfRelativeSD as Win32MakeSelfRelativeSD;
+use winapi::um::winbase::{LocalAlloc, LocalFree, LMEM_FIXED};
+use winapi::um::winnt::SECURITY_DESCRIPTOR;
+
+/// Wraps [`MakeSelfRelativeSD`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-makeselfrelativesd)
+///
+/// Converts an absolute `SECURITY_DESCRIPTOR` (built via
+/// `InitializeSecurityDescriptor` and the `SetSecurityDescriptor*` wrappers)
+/// into a self-relative one. The result is allocated with `LocalAlloc`, so it
+/// can be wrapped in a `LocalBox<SecurityDescriptor>` like any other
+/// descriptor returned by this crate.
+#[allow(non_snake_case)]
+pub fn MakeSelfRelativeSD(
+    absolute: &SECURITY_DESCRIPTOR,
+) -> io::Result<LocalBox<SecurityDescriptor>> {
+    let absolute_ptr = absolute as *const _ as *mut _;
+    let mut buffer_len: u32 = 0;
+
+    // Assumptions:
+    // - With a null destination buffer, this call always fails and fills in
+    //   the required buffer_len
+    unsafe { Win32MakeSelfRelativeSD(absolute_ptr, ptr::null_mut(), &mut buffer_len) };
+    if buffer_len == 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    let relative_ptr = unsafe { LocalAlloc(LMEM_FIXED, buffer_len as usize) };
+    if relative_ptr.is_null() {
+        return Err(io::Error::last_os_error());
+    }
+
+    let result =
+        unsafe { Win32MakeSelfRelativeSD(absolute_ptr, relative_ptr as *mut _, &mut buffer_len) };
+
+    if result != 0 {
+        Ok(unsafe { LocalBox::from_raw(relative_ptr as *mut _) })
+    } else {
+        let err = io::Error::last_os_error();
+        unsafe { LocalFree(relative_ptr) };
+        Err(err)
+    }
+}
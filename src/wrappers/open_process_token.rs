@@ -0,0 +1,22 @@
+use crate::structures::token::Token;
+use std::io;
+use std::ptr;
+use winapi::shared::ntdef::HANDLE;
+use winapi::um::processthreadsapi::{GetCurrentProcess, OpenProcessToken as Win32OpenProcessToken};
+use winapi::um::winnt::TOKEN_QUERY;
+
+/// Wraps [`OpenProcessToken`](https://docs.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-openprocesstoken)
+///
+/// Opens a query-only token for the current process.
+#[allow(non_snake_case)]
+pub fn OpenProcessToken() -> io::Result<Token> {
+    let mut handle: HANDLE = ptr::null_mut();
+
+    let result = unsafe { Win32OpenProcessToken(GetCurrentProcess(), TOKEN_QUERY, &mut handle) };
+
+    if result != 0 {
+        Ok(unsafe { Token::from_raw_handle(handle) })
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
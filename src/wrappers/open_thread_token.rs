@@ -0,0 +1,32 @@
+use crate::structures::token::Token;
+use std::io;
+use std::ptr;
+use winapi::shared::ntdef::HANDLE;
+use winapi::um::processthreadsapi::GetCurrentThread;
+use winapi::um::securitybaseapi::OpenThreadToken as Win32OpenThreadToken;
+use winapi::um::winnt::TOKEN_QUERY;
+
+/// Wraps [`OpenThreadToken`](https://docs.microsoft.com/en-us/windows/win32/api/processthreadsapi/nf-processthreadsapi-openthreadtoken)
+///
+/// Opens a query-only token for the calling thread, which must already be
+/// impersonating (see `wrappers::ImpersonateSelf`). `open_as_self` is
+/// forwarded to the Win32 `OpenAsSelf` parameter.
+#[allow(non_snake_case)]
+pub fn OpenThreadToken(open_as_self: bool) -> io::Result<Token> {
+    let mut handle: HANDLE = ptr::null_mut();
+
+    let result = unsafe {
+        Win32OpenThreadToken(
+            GetCurrentThread(),
+            TOKEN_QUERY,
+            open_as_self as i32,
+            &mut handle,
+        )
+    };
+
+    if result != 0 {
+        Ok(unsafe { Token::from_raw_handle(handle) })
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
@@ -0,0 +1,14 @@
+use std::io;
+use winapi::um::securitybaseapi::RevertToSelf as Win32RevertToSelf;
+
+/// Wraps [`RevertToSelf`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-reverttoself)
+#[allow(non_snake_case)]
+pub fn RevertToSelf() -> io::Result<()> {
+    let result = unsafe { Win32RevertToSelf() };
+
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
@@ -0,0 +1,124 @@
+use crate::constants::{SeObjectType, SecurityInformation};
+use crate::{Acl, Sid};
+use std::ffi::OsStr;
+use std::io;
+use std::os::windows::ffi::OsStrExt;
+use std::ptr;
+use winapi::shared::winerror::ERROR_SUCCESS;
+use winapi::um::accctrl::SE_OBJECT_TYPE;
+use winapi::um::aclapi::SetNamedSecurityInfoW;
+use winapi::um::winnt::PSID;
+
+/// Wraps [`SetNamedSecurityInfoW`](https://docs.microsoft.com/en-us/windows/win32/api/aclapi/nf-aclapi-setnamedsecurityinfow)
+///
+/// Only the components selected by `info` are written to the object; pass
+/// `None` for any component that should be left untouched, or that isn't
+/// selected by `info` in the first place.
+#[allow(non_snake_case)]
+pub fn SetNamedSecurityInfo(
+    path: &OsStr,
+    object_type: SeObjectType,
+    info: SecurityInformation,
+    owner: Option<&Sid>,
+    group: Option<&Sid>,
+    dacl: Option<&Acl>,
+    sacl: Option<&Acl>,
+) -> io::Result<()> {
+    let mut wide_path: Vec<u16> = path.encode_wide().chain(Some(0)).collect();
+
+    let owner_ptr = owner.map_or(ptr::null_mut(), |s| s as *const Sid as PSID);
+    let group_ptr = group.map_or(ptr::null_mut(), |s| s as *const Sid as PSID);
+    let dacl_ptr = dacl.map_or(ptr::null_mut(), |a| a as *const Acl as *mut _);
+    let sacl_ptr = sacl.map_or(ptr::null_mut(), |a| a as *const Acl as *mut _);
+
+    // Assumptions:
+    // - wide_path is NUL-terminated, as required by the *W variant
+    let result = unsafe {
+        SetNamedSecurityInfoW(
+            wide_path.as_mut_ptr(),
+            object_type as SE_OBJECT_TYPE,
+            info.bits(),
+            owner_ptr,
+            group_ptr,
+            dacl_ptr,
+            sacl_ptr,
+        )
+    };
+
+    if result == ERROR_SUCCESS {
+        Ok(())
+    } else {
+        Err(io::Error::from_raw_os_error(result as i32))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{LocalBox, SecurityDescriptor};
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    fn unique_temp_path(tag: &str) -> std::path::PathBuf {
+        static COUNTER: AtomicU32 = AtomicU32::new(0);
+        let n = COUNTER.fetch_add(1, Ordering::Relaxed);
+        std::env::temp_dir().join(format!(
+            "windows-permissions-rs-set-named-{}-{}-{}",
+            tag,
+            std::process::id(),
+            n
+        ))
+    }
+
+    #[test]
+    fn empty_info_mask_is_a_noop() -> io::Result<()> {
+        let path = unique_temp_path("noop");
+        std::fs::File::create(&path)?;
+
+        let result = SetNamedSecurityInfo(
+            path.as_os_str(),
+            SeObjectType::SE_FILE_OBJECT,
+            SecurityInformation::empty(),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        std::fs::remove_file(&path)?;
+        result
+    }
+
+    #[test]
+    fn dacl_round_trips_through_path() -> io::Result<()> {
+        let path = unique_temp_path("dacl-round-trip");
+        std::fs::File::create(&path)?;
+
+        let sd: LocalBox<SecurityDescriptor> = "D:(A;;FA;;;WD)".parse()?;
+        let result = (|| -> io::Result<()> {
+            SetNamedSecurityInfo(
+                path.as_os_str(),
+                SeObjectType::SE_FILE_OBJECT,
+                SecurityInformation::Dacl,
+                None,
+                None,
+                sd.dacl(),
+                None,
+            )?;
+
+            // lookup_path also fetches the owner and group, so compare only
+            // the DACL part of each descriptor
+            let dacl_sddl = |sd: &SecurityDescriptor| {
+                crate::wrappers::ConvertSecurityDescriptorToStringSecurityDescriptor(
+                    sd,
+                    SecurityInformation::Dacl,
+                )
+            };
+            let applied = SecurityDescriptor::lookup_path(&path)?;
+            assert_eq!(dacl_sddl(&*applied)?, dacl_sddl(&*sd)?);
+            Ok(())
+        })();
+
+        std::fs::remove_file(&path)?;
+        result
+    }
+}
@@ -0,0 +1,28 @@
+use crate::Acl;
+use std::io;
+use std::ptr;
+use winapi::um::securitybaseapi::SetSecurityDescriptorDacl as Win32SetSecurityDescriptorDacl;
+use winapi::um::winnt::SECURITY_DESCRIPTOR;
+
+/// Wraps [`SetSecurityDescriptorDacl`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-setsecuritydescriptordacl)
+///
+/// `dacl` of `None` marks `sd` as having no DACL, rather than an empty one.
+#[allow(non_snake_case)]
+pub fn SetSecurityDescriptorDacl(
+    sd: &mut SECURITY_DESCRIPTOR,
+    dacl: Option<&Acl>,
+) -> io::Result<()> {
+    let (present, dacl_ptr) = match dacl {
+        Some(acl) => (1, acl as *const Acl as *mut _),
+        None => (0, ptr::null_mut()),
+    };
+
+    let result =
+        unsafe { Win32SetSecurityDescriptorDacl(sd as *mut _ as *mut _, present, dacl_ptr, 0) };
+
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
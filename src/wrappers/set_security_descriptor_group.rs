@@ -0,0 +1,24 @@
+use crate::Sid;
+use std::io;
+use std::ptr;
+use winapi::um::securitybaseapi::SetSecurityDescriptorGroup as Win32SetSecurityDescriptorGroup;
+use winapi::um::winnt::{PSID, SECURITY_DESCRIPTOR};
+
+/// Wraps [`SetSecurityDescriptorGroup`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-setsecuritydescriptorgroup)
+///
+/// `group` of `None` clears the group on `sd`.
+#[allow(non_snake_case)]
+pub fn SetSecurityDescriptorGroup(
+    sd: &mut SECURITY_DESCRIPTOR,
+    group: Option<&Sid>,
+) -> io::Result<()> {
+    let group_ptr = group.map_or(ptr::null_mut(), |s| s as *const Sid as PSID);
+
+    let result = unsafe { Win32SetSecurityDescriptorGroup(sd as *mut _ as *mut _, group_ptr, 0) };
+
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::Sid;
+use std::io;
+use std::ptr;
+use winapi::um::securitybaseapi::SetSecurityDescriptorOwner as Win32SetSecurityDescriptorOwner;
+use winapi::um::winnt::{PSID, SECURITY_DESCRIPTOR};
+
+/// Wraps [`SetSecurityDescriptorOwner`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-setsecuritydescriptorowner)
+///
+/// `owner` of `None` clears the owner on `sd`.
+#[allow(non_snake_case)]
+pub fn SetSecurityDescriptorOwner(
+    sd: &mut SECURITY_DESCRIPTOR,
+    owner: Option<&Sid>,
+) -> io::Result<()> {
+    let owner_ptr = owner.map_or(ptr::null_mut(), |s| s as *const Sid as PSID);
+
+    let result = unsafe { Win32SetSecurityDescriptorOwner(sd as *mut _ as *mut _, owner_ptr, 0) };
+
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
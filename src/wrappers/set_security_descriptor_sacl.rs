@@ -0,0 +1,28 @@
+use crate::Acl;
+use std::io;
+use std::ptr;
+use winapi::um::securitybaseapi::SetSecurityDescriptorSacl as Win32SetSecurityDescriptorSacl;
+use winapi::um::winnt::SECURITY_DESCRIPTOR;
+
+/// Wraps [`SetSecurityDescriptorSacl`](https://docs.microsoft.com/en-us/windows/win32/api/securitybaseapi/nf-securitybaseapi-setsecuritydescriptorsacl)
+///
+/// `sacl` of `None` marks `sd` as having no SACL, rather than an empty one.
+#[allow(non_snake_case)]
+pub fn SetSecurityDescriptorSacl(
+    sd: &mut SECURITY_DESCRIPTOR,
+    sacl: Option<&Acl>,
+) -> io::Result<()> {
+    let (present, sacl_ptr) = match sacl {
+        Some(acl) => (1, acl as *const Acl as *mut _),
+        None => (0, ptr::null_mut()),
+    };
+
+    let result =
+        unsafe { Win32SetSecurityDescriptorSacl(sd as *mut _ as *mut _, present, sacl_ptr, 0) };
+
+    if result != 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}